@@ -0,0 +1,220 @@
+use criterion::{
+    criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, SamplingMode, Throughput,
+};
+use rusqlite::{params, params_from_iter, Connection, Result};
+use std::time::Duration;
+use your_sqlite_bench::data_gen::{self, Value};
+
+/// Creates and initializes the database with the users table.
+///
+/// Mirrors `setup_database` in the binary so a benchmark iteration can rebuild
+/// a fresh database in its (untimed) setup closure.
+fn setup_database(conn: &Connection) -> Result<()> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS users (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            email TEXT NOT NULL,
+            age INTEGER NOT NULL,
+            data BLOB
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Builds a fresh in-memory database, optionally pre-populating `rows` users.
+///
+/// Returned to the timed routine by the per-iteration setup closures below so
+/// that table creation and seed inserts never count against the measurement.
+fn fresh_db(rows: usize) -> Connection {
+    let conn = Connection::open_in_memory().expect("open in-memory db");
+    setup_database(&conn).expect("setup database");
+    if rows > 0 {
+        seed_users(&conn, rows).expect("seed users");
+    }
+    conn
+}
+
+/// Inserts `count` users inside a single transaction.
+///
+/// Rows are drawn from [`data_gen::users_spec`] — the same generator the binary
+/// uses — so the seeded table carries the non-uniform names, derived emails and
+/// skewed ages the uniform `format!("User{}", i)` workload was replaced to
+/// measure. A fixed seed keeps the fixture reproducible across iterations.
+fn seed_users(conn: &Connection, count: usize) -> Result<()> {
+    batch_insert(conn, count)
+}
+
+/// Runs `count` generated INSERTs inside a single transaction against `conn`.
+fn batch_insert(conn: &Connection, count: usize) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    for row in data_gen::users_spec(count, 0xB17E) {
+        let bound: Vec<rusqlite::types::Value> = row.into_iter().map(Value::into_sql).collect();
+        tx.execute(
+            "INSERT INTO users (name, email, age) VALUES (?1, ?2, ?3)",
+            params_from_iter(bound.iter()),
+        )?;
+    }
+    tx.commit()
+}
+
+/// Runs `count` single-statement INSERTs without an explicit transaction.
+fn single_inserts(conn: &Connection, count: usize) -> Result<()> {
+    for i in 0..count {
+        conn.execute(
+            "INSERT INTO users (name, email, age) VALUES (?1, ?2, ?3)",
+            params![
+                format!("SingleUser{}", i),
+                format!("single{}@example.com", i),
+                25 + (i % 40) as i32
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+fn simple_select(conn: &Connection) -> Result<usize> {
+    let mut stmt = conn.prepare("SELECT * FROM users WHERE age > ?1")?;
+    let mut rows = stmt.query([30])?;
+    let mut count = 0;
+    while rows.next()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn complex_select(conn: &Connection) -> Result<usize> {
+    let mut stmt = conn.prepare(
+        "SELECT age, COUNT(*) as count, AVG(age) as avg_age
+         FROM users
+         WHERE age BETWEEN ?1 AND ?2
+         GROUP BY age
+         ORDER BY count DESC
+         LIMIT 10",
+    )?;
+    let mut rows = stmt.query([25, 50])?;
+    let mut count = 0;
+    while rows.next()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn batch_update(conn: &Connection, count: usize) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    for i in 0..count {
+        tx.execute(
+            "UPDATE users SET age = ?1 WHERE id = ?2",
+            params![30 + (i % 30) as i32, i + 1],
+        )?;
+    }
+    tx.commit()
+}
+
+fn batch_delete(conn: &Connection, count: usize) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    tx.execute("DELETE FROM users WHERE id <= ?1", params![count])?;
+    tx.commit()
+}
+
+/// Destructive write workloads. Each uses `iter_batched` with `BatchSize::PerIteration`
+/// so the database is rebuilt fresh in the untimed setup closure before every measured
+/// iteration — otherwise the second iteration of batch-delete would delete nothing and
+/// batch-insert would grow the table without bound.
+fn bench_writes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("writes");
+    group.sampling_mode(SamplingMode::Flat);
+    group.sample_size(20);
+    group.warm_up_time(Duration::from_secs(1));
+
+    let insert_count = 10_000usize;
+    group.throughput(Throughput::Elements(insert_count as u64));
+    group.bench_with_input(
+        BenchmarkId::new("batch_insert", insert_count),
+        &insert_count,
+        |b, &count| {
+            b.iter_batched(
+                || fresh_db(0),
+                |conn| batch_insert(&conn, count).unwrap(),
+                BatchSize::PerIteration,
+            );
+        },
+    );
+
+    let single_count = 1_000usize;
+    group.throughput(Throughput::Elements(single_count as u64));
+    group.bench_with_input(
+        BenchmarkId::new("single_inserts", single_count),
+        &single_count,
+        |b, &count| {
+            b.iter_batched(
+                || fresh_db(0),
+                |conn| single_inserts(&conn, count).unwrap(),
+                BatchSize::PerIteration,
+            );
+        },
+    );
+
+    let update_count = 5_000usize;
+    group.throughput(Throughput::Elements(update_count as u64));
+    group.bench_with_input(
+        BenchmarkId::new("batch_update", update_count),
+        &update_count,
+        |b, &count| {
+            b.iter_batched(
+                || fresh_db(count),
+                |conn| batch_update(&conn, count).unwrap(),
+                BatchSize::PerIteration,
+            );
+        },
+    );
+
+    let delete_count = 5_000usize;
+    group.throughput(Throughput::Elements(delete_count as u64));
+    group.bench_with_input(
+        BenchmarkId::new("batch_delete", delete_count),
+        &delete_count,
+        |b, &count| {
+            b.iter_batched(
+                || fresh_db(count),
+                |conn| batch_delete(&conn, count).unwrap(),
+                BatchSize::PerIteration,
+            );
+        },
+    );
+
+    group.finish();
+}
+
+/// Read workloads. Each iteration gets a freshly seeded database from the untimed
+/// setup closure (`iter_batched_ref` with `BatchSize::PerIteration`), so only the
+/// query itself is measured.
+fn bench_reads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reads");
+    group.sampling_mode(SamplingMode::Flat);
+    group.sample_size(30);
+    group.warm_up_time(Duration::from_secs(1));
+
+    let rows = 10_000usize;
+    group.throughput(Throughput::Elements(rows as u64));
+    group.bench_function("simple_select", |b| {
+        b.iter_batched_ref(
+            || fresh_db(rows),
+            |conn| simple_select(conn).unwrap(),
+            BatchSize::PerIteration,
+        );
+    });
+    group.bench_function("complex_select", |b| {
+        b.iter_batched_ref(
+            || fresh_db(rows),
+            |conn| complex_select(conn).unwrap(),
+            BatchSize::PerIteration,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_writes, bench_reads);
+criterion_main!(benches);
@@ -0,0 +1,161 @@
+//! Injectable time sources for the benchmark harness.
+//!
+//! Timing used to be hardwired to `std::time::Instant::now()`, which makes the
+//! aggregation and reporting logic impossible to unit-test and pins the suite to
+//! a single clock source. [`Clocks`] abstracts the two time domains the harness
+//! cares about — a monotonic source for measuring elapsed work and a wall-clock
+//! source for stamping results — so a real implementation can be swapped for a
+//! [`SimulatedClocks`] that advances on command.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant, SystemTime};
+
+/// A point in time in nanoseconds, `Instant`-like but constructible by tests.
+#[derive(Clone, Copy)]
+pub struct Tick(u128);
+
+impl Tick {
+    /// The duration elapsed from `earlier` to `self`, saturating at zero.
+    pub fn duration_since(self, earlier: Tick) -> Duration {
+        Duration::from_nanos(self.0.saturating_sub(earlier.0) as u64)
+    }
+}
+
+/// A source of monotonic and wall-clock time.
+pub trait Clocks {
+    /// A monotonic reading suitable for measuring elapsed intervals.
+    fn monotonic(&self) -> Tick;
+    /// A wall-clock reading suitable for timestamping results.
+    fn realtime(&self) -> Tick;
+}
+
+/// The production clock: monotonic readings come from `Instant`, wall-clock from
+/// `SystemTime`.
+pub struct SystemClocks {
+    base: Instant,
+}
+
+impl SystemClocks {
+    pub fn new() -> Self {
+        SystemClocks {
+            base: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClocks {
+    fn default() -> Self {
+        SystemClocks::new()
+    }
+}
+
+impl Clocks for SystemClocks {
+    fn monotonic(&self) -> Tick {
+        Tick(self.base.elapsed().as_nanos())
+    }
+
+    fn realtime(&self) -> Tick {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        Tick(nanos)
+    }
+}
+
+/// A deterministic clock for tests. Both time domains read from the same
+/// interior counter, which only moves when [`SimulatedClocks::advance`] is called.
+pub struct SimulatedClocks {
+    nanos: Cell<u128>,
+}
+
+impl SimulatedClocks {
+    pub fn new() -> Self {
+        SimulatedClocks {
+            nanos: Cell::new(0),
+        }
+    }
+
+    /// Advances the simulated clock by `by`.
+    pub fn advance(&self, by: Duration) {
+        self.nanos.set(self.nanos.get() + by.as_nanos());
+    }
+}
+
+impl Default for SimulatedClocks {
+    fn default() -> Self {
+        SimulatedClocks::new()
+    }
+}
+
+impl Clocks for SimulatedClocks {
+    fn monotonic(&self) -> Tick {
+        Tick(self.nanos.get())
+    }
+
+    fn realtime(&self) -> Tick {
+        Tick(self.nanos.get())
+    }
+}
+
+/// A deterministic clock whose monotonic reading advances by a fixed `step` on
+/// every call. Each core `benchmark_*` workload reads the clock once at the
+/// start and once at the end of its work, so with a stepping clock it observes
+/// exactly one step of elapsed time regardless of how long the SQLite work
+/// actually took — which lets a test assert the reported timings without
+/// depending on machine speed. `benchmark_custom_query` also reads the clock
+/// around each filter profile, so it observes more than one step.
+pub struct SteppingClocks {
+    step: Duration,
+    nanos: Cell<u128>,
+}
+
+impl SteppingClocks {
+    pub fn new(step: Duration) -> Self {
+        SteppingClocks {
+            step,
+            nanos: Cell::new(0),
+        }
+    }
+}
+
+impl Clocks for SteppingClocks {
+    fn monotonic(&self) -> Tick {
+        let now = self.nanos.get();
+        self.nanos.set(now + self.step.as_nanos());
+        Tick(now)
+    }
+
+    fn realtime(&self) -> Tick {
+        Tick(self.nanos.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulated_clock_measures_known_advances() {
+        let clock = SimulatedClocks::new();
+        let start = clock.monotonic();
+        clock.advance(Duration::from_millis(250));
+        clock.advance(Duration::from_millis(750));
+        let elapsed = clock.monotonic().duration_since(start);
+        assert_eq!(elapsed, Duration::from_secs(1));
+        assert_eq!(elapsed.as_millis(), 1000);
+        // Both time domains read the same interior counter.
+        assert_eq!(
+            clock.realtime().duration_since(start),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn stepping_clock_reports_one_step_per_interval() {
+        let clock = SteppingClocks::new(Duration::from_millis(7));
+        let start = clock.monotonic();
+        let end = clock.monotonic();
+        assert_eq!(end.duration_since(start).as_millis(), 7);
+    }
+}
@@ -0,0 +1,350 @@
+//! Synthetic data generation for the insert benchmarks.
+//!
+//! The original insert workload fabricated rows with `format!("User{}", i)` and
+//! `20 + (i % 50)`, producing perfectly uniform, highly compressible values that
+//! hide real index fan-out and text-storage behaviour. This module synthesizes
+//! non-degenerate column values from configurable per-column models, driven by a
+//! seeded PRNG so runs are reproducible.
+
+/// Small, fast, seedable PRNG (SplitMix64). Kept inline so the benchmark keeps
+/// its single-dependency (`rusqlite`) footprint rather than pulling in `rand`.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Avoid a zero state, which degenerates for some mixers.
+        Rng {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        // 53 bits of mantissa precision.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform integer in `[low, high]` inclusive.
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        debug_assert!(high >= low);
+        let span = (high - low + 1) as u64;
+        low + (self.next_u64() % span) as i64
+    }
+}
+
+/// A token model that synthesizes plausible words by chaining character bigrams
+/// observed in a supplied word list. It is deliberately tiny — enough to break
+/// the "all rows compress to nothing" property without pretending to be a real
+/// language model.
+pub struct TokenModel {
+    /// For each leading character, the characters that followed it (with repeats,
+    /// so frequency acts as the weight). A `None` key holds valid word-initial
+    /// characters; a `None` value marks a word boundary.
+    transitions: std::collections::HashMap<Option<char>, Vec<Option<char>>>,
+}
+
+impl TokenModel {
+    /// Trains a bigram model on `words`.
+    pub fn train(words: &[&str]) -> Self {
+        let mut transitions: std::collections::HashMap<Option<char>, Vec<Option<char>>> =
+            std::collections::HashMap::new();
+        for word in words {
+            let mut prev: Option<char> = None;
+            for ch in word.chars() {
+                transitions.entry(prev).or_default().push(Some(ch));
+                prev = Some(ch);
+            }
+            // Record the boundary after the final character.
+            transitions.entry(prev).or_default().push(None);
+        }
+        TokenModel { transitions }
+    }
+
+    /// Generates a single token of at most `max_len` characters.
+    pub fn synth(&self, rng: &mut Rng, max_len: usize) -> String {
+        let mut out = String::new();
+        let mut prev: Option<char> = None;
+        for _ in 0..max_len {
+            let Some(choices) = self.transitions.get(&prev) else {
+                break;
+            };
+            if choices.is_empty() {
+                break;
+            }
+            let pick = &choices[(rng.next_u64() as usize) % choices.len()];
+            match pick {
+                Some(ch) => {
+                    out.push(*ch);
+                    prev = Some(*ch);
+                }
+                None if !out.is_empty() => break,
+                None => {
+                    // Boundary drawn before emitting anything; retry from start.
+                    prev = None;
+                }
+            }
+        }
+        if out.is_empty() {
+            out.push('x');
+        }
+        out
+    }
+
+    /// Capitalizes the first character of a synthesized token.
+    fn synth_name(&self, rng: &mut Rng, max_len: usize) -> String {
+        let token = self.synth(rng, max_len);
+        let mut chars = token.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => token,
+        }
+    }
+}
+
+/// A categorical sampler over integer-valued buckets with integer weights.
+pub struct Categorical {
+    values: Vec<i64>,
+    cumulative: Vec<u64>,
+    total: u64,
+}
+
+impl Categorical {
+    pub fn new(weighted: &[(i64, u64)]) -> Self {
+        let mut values = Vec::with_capacity(weighted.len());
+        let mut cumulative = Vec::with_capacity(weighted.len());
+        let mut total = 0u64;
+        for (value, weight) in weighted {
+            total += *weight;
+            values.push(*value);
+            cumulative.push(total);
+        }
+        Categorical {
+            values,
+            cumulative,
+            total: total.max(1),
+        }
+    }
+
+    fn sample(&self, rng: &mut Rng) -> i64 {
+        let needle = rng.next_u64() % self.total;
+        let idx = self
+            .cumulative
+            .iter()
+            .position(|c| needle < *c)
+            .unwrap_or(self.values.len() - 1);
+        self.values[idx]
+    }
+}
+
+/// Selector for foreign-key-style integer columns.
+pub enum Selector {
+    /// Uniform over `[1, cardinality]`.
+    Uniform { cardinality: i64 },
+    /// Zipfian over `[1, cardinality]` with exponent `s` — a few keys dominate,
+    /// like a realistic skewed foreign key.
+    Zipf { cardinality: i64, s: f64 },
+}
+
+impl Selector {
+    fn sample(&self, rng: &mut Rng) -> i64 {
+        match self {
+            Selector::Uniform { cardinality } => rng.range(1, *cardinality),
+            Selector::Zipf { cardinality, s } => {
+                // Rejection-free inverse sampling over the normalized harmonic.
+                let n = *cardinality;
+                let mut denom = 0.0f64;
+                for k in 1..=n {
+                    denom += 1.0 / (k as f64).powf(*s);
+                }
+                let target = rng.next_f64() * denom;
+                let mut acc = 0.0f64;
+                for k in 1..=n {
+                    acc += 1.0 / (k as f64).powf(*s);
+                    if acc >= target {
+                        return k;
+                    }
+                }
+                n
+            }
+        }
+    }
+}
+
+/// Per-column generation specification.
+pub enum ColumnSpec {
+    /// Free text / names synthesized from a token model, capped at `max_len`.
+    TokenModel { model: TokenModel, max_len: usize },
+    /// A weighted categorical label (e.g. age buckets).
+    Categorical(Categorical),
+    /// A uniform integer in an inclusive range.
+    IntegerRange { low: i64, high: i64 },
+    /// A foreign-key-style integer selector.
+    ForeignKey(Selector),
+    /// An email address derived from the value of an earlier `TokenModel` or
+    /// `Categorical` column named `source`; any other source yields `user`.
+    EmailFromName { source: String, domain: String },
+}
+
+/// A typed column value spanning every storage class the benchmark binds:
+/// text, blob, integer, real and NULL. Used both by the generators here and as
+/// the generic parameter type the binding helper feeds to `rusqlite`.
+pub enum Value {
+    Text(String),
+    Blob(Vec<u8>),
+    Integer(i64),
+    Real(f64),
+    Null,
+}
+
+impl Value {
+    /// Converts into the `rusqlite` value the driver binds. This is the single
+    /// binding helper through which every generated parameter reaches SQLite, so
+    /// BLOB and NULL columns travel the same path as text and integers.
+    pub fn into_sql(self) -> rusqlite::types::Value {
+        match self {
+            Value::Text(text) => rusqlite::types::Value::Text(text),
+            Value::Blob(bytes) => rusqlite::types::Value::Blob(bytes),
+            Value::Integer(n) => rusqlite::types::Value::Integer(n),
+            Value::Real(r) => rusqlite::types::Value::Real(r),
+            Value::Null => rusqlite::types::Value::Null,
+        }
+    }
+}
+
+impl Rng {
+    /// Fills a fresh buffer of `size` bytes with pseudo-random data — used to
+    /// populate BLOB columns so the insert path exercises page overflow and
+    /// large-record storage the way non-trivial payloads do.
+    pub fn blob(&mut self, size: usize) -> Vec<u8> {
+        let mut out = Vec::with_capacity(size);
+        while out.len() < size {
+            let word = self.next_u64().to_le_bytes();
+            let take = (size - out.len()).min(word.len());
+            out.extend_from_slice(&word[..take]);
+        }
+        out
+    }
+}
+
+/// A named column together with its generation spec.
+pub struct Column {
+    pub name: String,
+    pub spec: ColumnSpec,
+}
+
+/// Streams generated rows from a per-column spec and a seed.
+pub struct RowGenerator {
+    columns: Vec<Column>,
+    rng: Rng,
+    remaining: usize,
+}
+
+impl RowGenerator {
+    pub fn new(seed: u64, rows: usize, columns: Vec<Column>) -> Self {
+        RowGenerator {
+            columns,
+            rng: Rng::new(seed),
+            remaining: rows,
+        }
+    }
+
+    fn generate_row(&mut self) -> Vec<Value> {
+        let mut row: Vec<Value> = Vec::with_capacity(self.columns.len());
+        let mut by_name: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        for col in &self.columns {
+            let value = match &col.spec {
+                ColumnSpec::TokenModel { model, max_len } => {
+                    let text = model.synth_name(&mut self.rng, *max_len);
+                    by_name.insert(col.name.clone(), text.clone());
+                    Value::Text(text)
+                }
+                ColumnSpec::Categorical(cat) => {
+                    let n = cat.sample(&mut self.rng);
+                    by_name.insert(col.name.clone(), n.to_string());
+                    Value::Integer(n)
+                }
+                ColumnSpec::IntegerRange { low, high } => {
+                    Value::Integer(self.rng.range(*low, *high))
+                }
+                ColumnSpec::ForeignKey(sel) => Value::Integer(sel.sample(&mut self.rng)),
+                ColumnSpec::EmailFromName { source, domain } => {
+                    let local = by_name
+                        .get(source)
+                        .map(|s| s.to_lowercase())
+                        .unwrap_or_else(|| "user".to_string());
+                    Value::Text(format!("{}@{}", local, domain))
+                }
+            };
+            row.push(value);
+        }
+        row
+    }
+}
+
+impl Iterator for RowGenerator {
+    type Item = Vec<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.generate_row())
+    }
+}
+
+/// Builds the column spec matching the `users` table: a token-model `name`, an
+/// `email` derived from it, and a skewed `age`. Serves as the default generator
+/// for `benchmark_batch_insert`.
+pub fn users_spec(rows: usize, seed: u64) -> RowGenerator {
+    // A compact training corpus; enough bigram variety for non-uniform names.
+    const CORPUS: &[&str] = &[
+        "amelia", "benjamin", "chloe", "daniel", "eleanor", "frances", "gabriel", "harper",
+        "isabella", "jackson", "katherine", "lucas", "matilda", "nathaniel", "olivia", "patrick",
+        "quinn", "rosalind", "sebastian", "theodore", "ursula", "vincent", "william", "ximena",
+        "yasmine", "zachary",
+    ];
+    let model = TokenModel::train(CORPUS);
+    let columns = vec![
+        Column {
+            name: "name".to_string(),
+            spec: ColumnSpec::TokenModel {
+                model,
+                max_len: 12,
+            },
+        },
+        Column {
+            name: "email".to_string(),
+            spec: ColumnSpec::EmailFromName {
+                source: "name".to_string(),
+                domain: "example.com".to_string(),
+            },
+        },
+        Column {
+            name: "age".to_string(),
+            // Skew towards working-age adults rather than a flat band. The
+            // buckets are bound as integers so they match the column's INTEGER
+            // storage class instead of relying on affinity to coerce text.
+            spec: ColumnSpec::Categorical(Categorical::new(&[
+                (18, 5),
+                (25, 20),
+                (32, 25),
+                (40, 20),
+                (55, 15),
+                (70, 5),
+            ])),
+        },
+    ];
+    RowGenerator::new(seed, rows, columns)
+}
@@ -0,0 +1,181 @@
+//! `EXPLAIN QUERY PLAN` parsing and diagnosis.
+//!
+//! The custom queries do heavy multi-join `LEFT OUTER` work and `ORDER BY
+//! random()` with no reported index information, so a slow number gives no
+//! diagnosis. This module runs `EXPLAIN QUERY PLAN` for a statement, parses the
+//! returned rows into a node tree, and classifies each step — full table scan
+//! versus index search, and whether a temporary b-tree is materialized for
+//! `ORDER BY`/`GROUP BY` — so the user can see which cost is a missing index
+//! before touching the query.
+
+use rusqlite::{params_from_iter, types::Value, Connection, Result};
+
+/// How a single plan step accesses its data.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    /// Full table scan — no index used. Reported as a warning.
+    Scan,
+    /// Index search (`SEARCH ... USING INDEX`/covering index/primary key).
+    Search,
+    /// A materialized temporary b-tree for ordering or grouping. A warning.
+    TempBTree,
+    /// Anything else (compound-query bookkeeping, subquery labels, …).
+    Other,
+}
+
+/// One parsed `EXPLAIN QUERY PLAN` row with its position in the tree.
+pub struct PlanNode {
+    pub id: i64,
+    pub parent: i64,
+    pub detail: String,
+    pub kind: NodeKind,
+    /// Index name the step picked, if the detail names one.
+    pub index: Option<String>,
+    pub children: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    fn is_warning(&self) -> bool {
+        matches!(self.kind, NodeKind::Scan | NodeKind::TempBTree)
+    }
+}
+
+/// Classifies a raw detail string. Kept lenient about the exact wording so it
+/// survives SQLite version differences in the detail text.
+fn classify(detail: &str) -> NodeKind {
+    let upper = detail.to_uppercase();
+    if upper.contains("USE TEMP B-TREE") || upper.contains("USING TEMP B-TREE") {
+        NodeKind::TempBTree
+    } else if upper.starts_with("SEARCH") {
+        NodeKind::Search
+    } else if upper.starts_with("SCAN") {
+        // "SCAN t USING INDEX ix" is still an indexed access, not a full scan.
+        if upper.contains("USING INDEX")
+            || upper.contains("USING COVERING INDEX")
+            || upper.contains("USING INTEGER PRIMARY KEY")
+        {
+            NodeKind::Search
+        } else {
+            NodeKind::Scan
+        }
+    } else {
+        NodeKind::Other
+    }
+}
+
+/// Extracts the index name from a detail string, if one is named.
+fn extract_index(detail: &str) -> Option<String> {
+    // Matches "... USING [COVERING] INDEX <name>[ (...)]" case-insensitively
+    // without assuming a fixed prefix length.
+    let upper = detail.to_uppercase();
+    let marker = "USING ";
+    let pos = upper.find(marker)? + marker.len();
+    let rest = &detail[pos..];
+    let rest_upper = &upper[pos..];
+    let rest_upper = rest_upper.strip_prefix("COVERING ").unwrap_or(rest_upper);
+    let rest = if rest_upper.len() != rest.len() {
+        &rest[rest.len() - rest_upper.len()..]
+    } else {
+        rest
+    };
+    let rest_upper = rest_upper.strip_prefix("INDEX ")?;
+    let rest = &rest[rest.len() - rest_upper.len()..];
+    let name: String = rest
+        .chars()
+        .take_while(|c| !c.is_whitespace() && *c != '(')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Runs `EXPLAIN QUERY PLAN <sql>` with `params` bound and assembles the node tree.
+pub fn plan(conn: &Connection, sql: &str, params: &[Value]) -> Result<Vec<PlanNode>> {
+    let explained = format!("EXPLAIN QUERY PLAN {}", sql);
+    let mut stmt = conn.prepare(&explained)?;
+    let rows = stmt.query_map(params_from_iter(params.iter()), |row| {
+        let id: i64 = row.get(0)?;
+        let parent: i64 = row.get(1)?;
+        let detail: String = row.get(3)?;
+        Ok((id, parent, detail))
+    })?;
+
+    let mut flat: Vec<PlanNode> = Vec::new();
+    for row in rows {
+        let (id, parent, detail) = row?;
+        let kind = classify(&detail);
+        let index = extract_index(&detail);
+        flat.push(PlanNode {
+            id,
+            parent,
+            detail,
+            kind,
+            index,
+            children: Vec::new(),
+        });
+    }
+
+    Ok(build_tree(flat))
+}
+
+/// Folds a flat id/parent list into a tree. SQLite emits parents before their
+/// children, so a single reverse pass that finds each node's parent among the
+/// nodes not yet popped is sufficient.
+fn build_tree(flat: Vec<PlanNode>) -> Vec<PlanNode> {
+    // Attach each node to its parent by walking from the back, so children are
+    // moved into a parent that still lives in the working vector.
+    let mut roots: Vec<PlanNode> = Vec::new();
+    let mut pending = flat;
+    // Process in reverse so that when we pop a node its own children (which come
+    // later in EQP output) have already been re-parented under it.
+    while let Some(node) = pending.pop() {
+        if node.parent == 0 {
+            roots.push(node);
+        } else if let Some(parent) = pending.iter_mut().find(|p| p.id == node.parent) {
+            parent.children.push(node);
+        } else {
+            // Orphan (parent not found) — surface it at the top level.
+            roots.push(node);
+        }
+    }
+    roots.reverse();
+    for root in &mut roots {
+        sort_children(root);
+    }
+    roots
+}
+
+fn sort_children(node: &mut PlanNode) {
+    node.children.sort_by_key(|c| c.id);
+    for child in &mut node.children {
+        sort_children(child);
+    }
+}
+
+/// Prints a per-query plan report, flagging full scans and ordering temp b-trees.
+/// Returns the number of warnings emitted.
+pub fn report(conn: &Connection, label: &str, sql: &str, params: &[Value]) -> Result<usize> {
+    let roots = plan(conn, sql, params)?;
+    println!("  Plan for {}:", label);
+    let mut warnings = 0;
+    for root in &roots {
+        warnings += print_node(root, 2);
+    }
+    if warnings == 0 {
+        println!("    (no full scans or ordering temp b-trees)");
+    }
+    Ok(warnings)
+}
+
+fn print_node(node: &PlanNode, depth: usize) -> usize {
+    let indent = " ".repeat(depth);
+    let marker = if node.is_warning() { "⚠ " } else { "  " };
+    println!("{}{}{}", indent, marker, node.detail);
+    let mut warnings = usize::from(node.is_warning());
+    for child in &node.children {
+        warnings += print_node(child, depth + 2);
+    }
+    warnings
+}
@@ -0,0 +1,147 @@
+//! Dynamic filter query builder for the index-page listing.
+//!
+//! The original index query baked in `LIKE lower('%%')` across six columns plus
+//! a fixed date/limit/offset, so it only ever benchmarked the "no search term"
+//! path and always paid for every join. [`OptFilters`] describes an optional
+//! filter set and assembles only the join and `WHERE` fragments a given set
+//! actually needs, so the benchmark can time representative filter profiles
+//! (empty, text search, date-narrowed, actress-filtered) the way the app issues
+//! them.
+
+use rusqlite::types::Value;
+
+/// Column the listing is sorted by.
+#[derive(Clone, Copy)]
+pub enum SortColumn {
+    ReleaseDate,
+    DvdId,
+}
+
+impl SortColumn {
+    fn as_sql(self) -> &'static str {
+        match self {
+            SortColumn::ReleaseDate => "derived_video.release_date DESC",
+            SortColumn::DvdId => "derived_video.dvd_id ASC",
+        }
+    }
+}
+
+/// An optional filter set for the index-page listing. Every filter field left at
+/// its default contributes no SQL; `sort`, `limit` and `offset` are always
+/// emitted.
+pub struct OptFilters {
+    pub search_term: Option<String>,
+    pub release_date_from: Option<String>,
+    pub release_date_to: Option<String>,
+    pub require_jacket: bool,
+    pub actress_id: Option<i64>,
+    pub category_id: Option<i64>,
+    pub sort: SortColumn,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl Default for OptFilters {
+    fn default() -> Self {
+        OptFilters {
+            search_term: None,
+            release_date_from: None,
+            release_date_to: None,
+            require_jacket: false,
+            actress_id: None,
+            category_id: None,
+            sort: SortColumn::ReleaseDate,
+            limit: 100,
+            offset: 0,
+        }
+    }
+}
+
+impl OptFilters {
+    /// Assembles the SQL and its positional parameters, emitting only the joins
+    /// and predicates this filter set requires.
+    pub fn build(&self) -> (String, Vec<Value>) {
+        let mut params: Vec<Value> = Vec::new();
+
+        // The actress/category joins are only needed for a free-text search (it
+        // matches their names) or for an explicit id filter.
+        let need_actress = self.search_term.is_some() || self.actress_id.is_some();
+        let need_category = self.search_term.is_some() || self.category_id.is_some();
+
+        let mut sql = String::from(
+            "SELECT DISTINCT derived_video.dvd_id, derived_video.jacket_full_url, \
+             derived_video.release_date FROM derived_video",
+        );
+        if need_actress {
+            sql.push_str(
+                " LEFT OUTER JOIN derived_video_actress ON derived_video_actress.content_id = derived_video.content_id \
+                 LEFT OUTER JOIN derived_actress ON derived_actress.id = derived_video_actress.actress_id",
+            );
+        }
+        if need_category {
+            sql.push_str(
+                " LEFT OUTER JOIN derived_video_category ON derived_video_category.content_id = derived_video.content_id \
+                 LEFT OUTER JOIN derived_category ON derived_category.id = derived_video_category.category_id",
+            );
+        }
+
+        // Baseline predicates shared by every profile.
+        sql.push_str(
+            " WHERE derived_video.dvd_id IS NOT NULL AND derived_video.dvd_id IS NOT '' \
+             AND derived_video.release_date IS NOT NULL",
+        );
+
+        if self.require_jacket {
+            sql.push_str(" AND derived_video.jacket_full_url IS NOT NULL");
+        }
+
+        if let Some(from) = &self.release_date_from {
+            params.push(Value::Text(from.clone()));
+            sql.push_str(&format!(" AND derived_video.release_date >= ?{}", params.len()));
+        }
+        if let Some(to) = &self.release_date_to {
+            params.push(Value::Text(to.clone()));
+            sql.push_str(&format!(" AND derived_video.release_date <= ?{}", params.len()));
+        }
+
+        if let Some(term) = &self.search_term {
+            let like = format!("%{}%", term.to_lowercase());
+            // Six OR'd LIKEs, but only bound once and only emitted when searching.
+            params.push(Value::Text(like));
+            let idx = params.len();
+            sql.push_str(&format!(
+                " AND (lower(derived_video.dvd_id) LIKE ?{idx} \
+                 OR lower(derived_actress.name_romaji) LIKE ?{idx} \
+                 OR lower(derived_actress.name_kanji) LIKE ?{idx} \
+                 OR lower(derived_actress.name_kana) LIKE ?{idx} \
+                 OR lower(derived_category.name_en) LIKE ?{idx} \
+                 OR lower(derived_category.name_ja) LIKE ?{idx})",
+                idx = idx
+            ));
+        }
+
+        if let Some(actress_id) = self.actress_id {
+            params.push(Value::Integer(actress_id));
+            sql.push_str(&format!(
+                " AND derived_video_actress.actress_id = ?{}",
+                params.len()
+            ));
+        }
+        if let Some(category_id) = self.category_id {
+            params.push(Value::Integer(category_id));
+            sql.push_str(&format!(
+                " AND derived_video_category.category_id = ?{}",
+                params.len()
+            ));
+        }
+
+        sql.push_str(&format!(" ORDER BY {}", self.sort.as_sql()));
+
+        params.push(Value::Integer(self.limit));
+        sql.push_str(&format!(" LIMIT ?{}", params.len()));
+        params.push(Value::Integer(self.offset));
+        sql.push_str(&format!(" OFFSET ?{}", params.len()));
+
+        (sql, params)
+    }
+}
@@ -0,0 +1,12 @@
+//! Shared workload building blocks for the SQLite benchmark.
+//!
+//! The modules live in the library crate so the `your_sqlite_bench` binary and
+//! the Criterion `benches/sqlite_bench` target can share them. The binary uses
+//! all of them; the bench target draws its rows from the same data generator
+//! and keeps its own copies of the workload functions.
+
+pub mod clock;
+pub mod data_gen;
+pub mod explain;
+pub mod filters;
+pub mod pragma;
@@ -0,0 +1,169 @@
+//! Connection PRAGMA tuning.
+//!
+//! `Connection::open` leaves every PRAGMA at its SQLite default, so the raw
+//! benchmark only ever measures rollback-journal + `synchronous=FULL`. This
+//! module describes a named tuning configuration and applies it to a connection
+//! before `setup_database`, so the suite can be run once per config and the
+//! throughput differences compared directly.
+
+use rusqlite::{Connection, Result};
+
+/// `PRAGMA journal_mode` values relevant to the write benchmarks.
+#[derive(Clone, Copy)]
+pub enum JournalMode {
+    Delete,
+    Truncate,
+    Wal,
+    Memory,
+}
+
+impl JournalMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            JournalMode::Delete => "DELETE",
+            JournalMode::Truncate => "TRUNCATE",
+            JournalMode::Wal => "WAL",
+            JournalMode::Memory => "MEMORY",
+        }
+    }
+}
+
+/// `PRAGMA synchronous` levels.
+#[derive(Clone, Copy)]
+pub enum Synchronous {
+    Off,
+    Normal,
+    Full,
+}
+
+impl Synchronous {
+    fn as_str(self) -> &'static str {
+        match self {
+            Synchronous::Off => "OFF",
+            Synchronous::Normal => "NORMAL",
+            Synchronous::Full => "FULL",
+        }
+    }
+}
+
+/// `PRAGMA temp_store` backing.
+#[derive(Clone, Copy)]
+pub enum TempStore {
+    Default,
+    File,
+    Memory,
+}
+
+impl TempStore {
+    fn as_str(self) -> &'static str {
+        match self {
+            TempStore::Default => "DEFAULT",
+            TempStore::File => "FILE",
+            TempStore::Memory => "MEMORY",
+        }
+    }
+}
+
+/// A named set of connection PRAGMAs to apply before benchmarking.
+#[derive(Clone)]
+pub struct PragmaConfig {
+    pub name: String,
+    pub journal_mode: JournalMode,
+    pub synchronous: Synchronous,
+    pub cache_size: i64,
+    pub mmap_size: i64,
+    pub temp_store: TempStore,
+    pub page_size: i64,
+}
+
+impl PragmaConfig {
+    /// Starts a builder for a config named `name`, initialized to SQLite defaults.
+    pub fn builder(name: &str) -> PragmaConfigBuilder {
+        PragmaConfigBuilder {
+            config: PragmaConfig {
+                name: name.to_string(),
+                journal_mode: JournalMode::Delete,
+                synchronous: Synchronous::Full,
+                cache_size: -2000, // SQLite default: ~2 MiB expressed in KiB.
+                mmap_size: 0,
+                temp_store: TempStore::Default,
+                page_size: 4096,
+            },
+        }
+    }
+
+    /// Applies this configuration to `conn`.
+    ///
+    /// `page_size` is set first because it only takes effect before the database
+    /// file has any pages, and `journal_mode` follows so WAL is selected on the
+    /// freshly sized file.
+    pub fn apply(&self, conn: &Connection) -> Result<()> {
+        conn.pragma_update(None, "page_size", self.page_size)?;
+        conn.pragma_update(None, "journal_mode", self.journal_mode.as_str())?;
+        conn.pragma_update(None, "synchronous", self.synchronous.as_str())?;
+        conn.pragma_update(None, "cache_size", self.cache_size)?;
+        conn.pragma_update(None, "mmap_size", self.mmap_size)?;
+        conn.pragma_update(None, "temp_store", self.temp_store.as_str())?;
+        Ok(())
+    }
+}
+
+/// Builder for [`PragmaConfig`]; each setter overrides one PRAGMA.
+pub struct PragmaConfigBuilder {
+    config: PragmaConfig,
+}
+
+impl PragmaConfigBuilder {
+    pub fn journal_mode(mut self, mode: JournalMode) -> Self {
+        self.config.journal_mode = mode;
+        self
+    }
+
+    pub fn synchronous(mut self, level: Synchronous) -> Self {
+        self.config.synchronous = level;
+        self
+    }
+
+    pub fn cache_size(mut self, pages: i64) -> Self {
+        self.config.cache_size = pages;
+        self
+    }
+
+    pub fn mmap_size(mut self, bytes: i64) -> Self {
+        self.config.mmap_size = bytes;
+        self
+    }
+
+    pub fn temp_store(mut self, store: TempStore) -> Self {
+        self.config.temp_store = store;
+        self
+    }
+
+    pub fn page_size(mut self, bytes: i64) -> Self {
+        self.config.page_size = bytes;
+        self
+    }
+
+    pub fn build(self) -> PragmaConfig {
+        self.config
+    }
+}
+
+/// The named configurations the suite sweeps over: SQLite's defaults, a WAL +
+/// `synchronous=NORMAL` profile, and an in-memory-journal profile.
+pub fn named_configs() -> Vec<PragmaConfig> {
+    vec![
+        PragmaConfig::builder("default").build(),
+        PragmaConfig::builder("wal-normal")
+            .journal_mode(JournalMode::Wal)
+            .synchronous(Synchronous::Normal)
+            .cache_size(-64_000)
+            .mmap_size(256 * 1024 * 1024)
+            .build(),
+        PragmaConfig::builder("memory-off")
+            .journal_mode(JournalMode::Memory)
+            .synchronous(Synchronous::Off)
+            .temp_store(TempStore::Memory)
+            .build(),
+    ]
+}